@@ -0,0 +1,4 @@
+/// Opt-in compact binary encoding for recording/replaying `MarketEvent<PublicTrade>` streams.
+/// Enabled via the `binary-codec` feature flag; JSON remains the default wire/storage format.
+#[cfg(feature = "binary-codec")]
+pub mod binary;
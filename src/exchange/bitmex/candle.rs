@@ -0,0 +1,66 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{
+        bitmex::{message::BitmexMessage, Bitmex},
+        ExchangeId,
+    },
+    subscription::candle::{Candle, Candles},
+    transformer::StatelessTransformer,
+    ExchangeWsStream, StreamSelector,
+};
+use barter_integration::model::{Exchange, Instrument};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Bitmex`] real-time `tradeBin` (kline) WebSocket message.
+///
+/// See docs: <https://www.bitmex.com/app/wsAPI#Response-Format>
+pub type BitmexCandlePayload = BitmexMessage<BitmexCandle>;
+
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitmexCandle {
+    pub symbol: String,
+    pub timestamp: DateTime<Utc>,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+    pub trades: u64,
+}
+
+impl StreamSelector<Candles> for Bitmex {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, Candles, BitmexCandlePayload>>;
+}
+
+impl From<(ExchangeId, Instrument, BitmexCandlePayload)> for MarketIter<Candle> {
+    fn from(
+        (exchange_id, instrument, candles): (ExchangeId, Instrument, BitmexCandlePayload),
+    ) -> Self {
+        Self(
+            candles
+                .data
+                .into_iter()
+                .map(|candle| {
+                    Ok(MarketEvent {
+                        exchange_time: candle.timestamp,
+                        received_time: Utc::now(),
+                        exchange: Exchange::from(exchange_id),
+                        instrument: instrument.clone(),
+                        kind: Candle {
+                            // BitMEX's `tradeBin` carries the bucket's end timestamp only.
+                            start_time: candle.timestamp,
+                            end_time: candle.timestamp,
+                            open: candle.open,
+                            high: candle.high,
+                            low: candle.low,
+                            close: candle.close,
+                            volume: candle.volume,
+                            trade_count: candle.trades,
+                        },
+                    })
+                })
+                .collect(),
+        )
+    }
+}
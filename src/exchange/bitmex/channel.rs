@@ -0,0 +1,66 @@
+use super::Bitmex;
+use crate::{
+    subscription::{
+        candle::{Candles, Interval},
+        funding::FundingRates,
+        trade::PublicTrades,
+        Subscription,
+    },
+    Identifier,
+};
+use serde::Serialize;
+
+/// [`Bitmex`] topic prefix used when building the `"op": "subscribe"` request (eg/
+/// `"trade:XBTUSD"`, `"tradeBin1m:XBTUSD"`).
+///
+/// See docs: <https://www.bitmex.com/app/wsAPI#Subscriptions>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct BitmexChannel(pub &'static str);
+
+impl BitmexChannel {
+    /// [`Bitmex`] real-time trades channel.
+    pub const TRADES: Self = Self("trade");
+
+    /// [`Bitmex`] real-time funding channel.
+    pub const FUNDING: Self = Self("funding");
+
+    /// [`Bitmex`] `tradeBin` (kline) channel for the given [`Interval`].
+    ///
+    /// BitMEX only ships `tradeBin` buckets at `1m`/`5m`/`1h`/`1d` - see docs:
+    /// <https://www.bitmex.com/app/wsAPI#Subscriptions>. Panics for any other [`Interval`]
+    /// rather than silently aliasing the subscription to a bucket size the caller didn't ask
+    /// for.
+    pub fn candles(interval: Interval) -> Self {
+        match interval {
+            Interval::Minute1 => Self("tradeBin1m"),
+            Interval::Minute5 => Self("tradeBin5m"),
+            Interval::Hour1 => Self("tradeBin1h"),
+            Interval::Day1 => Self("tradeBin1d"),
+            unsupported => panic!("Bitmex has no tradeBin channel for {unsupported:?}"),
+        }
+    }
+}
+
+impl Identifier<BitmexChannel> for Subscription<Bitmex, PublicTrades> {
+    fn id(&self) -> BitmexChannel {
+        BitmexChannel::TRADES
+    }
+}
+
+impl Identifier<BitmexChannel> for Subscription<Bitmex, FundingRates> {
+    fn id(&self) -> BitmexChannel {
+        BitmexChannel::FUNDING
+    }
+}
+
+impl Identifier<BitmexChannel> for Subscription<Bitmex, Candles> {
+    fn id(&self) -> BitmexChannel {
+        BitmexChannel::candles(self.kind.0)
+    }
+}
+
+impl AsRef<str> for BitmexChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{bitmex::message::BitmexMessage, bitmex::Bitmex, ExchangeId},
+    subscription::funding::{Funding, FundingRates},
+    ExchangeWsStream, StreamSelector,
+};
+use crate::transformer::StatelessTransformer;
+use barter_integration::model::{Exchange, Instrument};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Bitmex`] real-time funding WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.bitmex.com/app/wsAPI#Response-Format>
+/// ```json
+/// {
+///     "table": "funding",
+///     "action": "insert",
+///     "data": [
+///         {
+///             "symbol": "XBTUSD",
+///             "fundingRate": 0.0001,
+///             "fundingRateDaily": 0.0003,
+///             "timestamp": "2023-02-18T09:00:00.000Z"
+///         }
+///     ]
+/// }
+/// ```
+pub type BitmexFundingPayload = BitmexMessage<BitmexFunding>;
+
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitmexFunding {
+    pub symbol: String,
+    #[serde(rename = "fundingRate")]
+    pub rate: f64,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl StreamSelector<FundingRates> for Bitmex {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, FundingRates, BitmexFundingPayload>>;
+}
+
+impl From<(ExchangeId, Instrument, BitmexFundingPayload)> for MarketIter<Funding> {
+    fn from(
+        (exchange_id, instrument, funding): (ExchangeId, Instrument, BitmexFundingPayload),
+    ) -> Self {
+        Self(
+            funding
+                .data
+                .into_iter()
+                .map(|funding| {
+                    Ok(MarketEvent {
+                        exchange_time: funding.timestamp,
+                        received_time: Utc::now(),
+                        exchange: Exchange::from(exchange_id),
+                        instrument: instrument.clone(),
+                        kind: Funding {
+                            rate: funding.rate,
+                            // BitMEX's `funding` table only carries the currently applied rate.
+                            next_rate: None,
+                            next_funding_time: funding.timestamp,
+                        },
+                    })
+                })
+                .collect(),
+        )
+    }
+}
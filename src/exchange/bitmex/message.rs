@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// Generic [`Bitmex`](super::Bitmex) WebSocket message envelope wrapping a `table`'s `data`
+/// payload, shared by trade, funding, and `tradeBin` (candle) channels.
+///
+/// See docs: <https://www.bitmex.com/app/wsAPI#Response-Format>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitmexMessage<T> {
+    pub table: String,
+    pub action: String,
+    pub data: Vec<T>,
+}
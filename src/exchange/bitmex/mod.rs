@@ -0,0 +1,73 @@
+use self::{channel::BitmexChannel, market::BitmexMarket, subscription::BitmexSubResponse};
+use crate::{
+    exchange::{ping::PingInterval, Connector, ExchangeId, ExchangeSub},
+    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
+};
+use barter_integration::protocol::websocket::WsMessage;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::time::Duration;
+
+pub mod candle;
+pub mod channel;
+pub mod funding;
+pub mod market;
+pub mod message;
+pub mod subscription;
+pub mod trade;
+
+/// [`Bitmex`] server base url.
+///
+/// See docs: <https://www.bitmex.com/app/wsAPI>
+pub const BASE_URL_BITMEX: &str = "wss://www.bitmex.com/realtime";
+
+/// [`Bitmex`] exchange.
+///
+/// See docs: <https://www.bitmex.com/app/wsAPI>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Bitmex;
+
+impl Bitmex {
+    /// BitMEX drops idle connections, so the client must send a `"ping"` text frame every 5
+    /// seconds to keep the WebSocket alive. Read by `Connector::ping_interval()`.
+    pub const PING_INTERVAL: PingInterval = PingInterval {
+        interval: Duration::from_secs(5),
+        message: bitmex_ping_message,
+    };
+}
+
+fn bitmex_ping_message() -> WsMessage {
+    WsMessage::Text("ping".to_string())
+}
+
+impl Connector for Bitmex {
+    const ID: ExchangeId = ExchangeId::Bitmex;
+    type Channel = BitmexChannel;
+    type Market = BitmexMarket;
+    type Subscriber = WebSocketSubscriber<Self::SubValidator>;
+    type SubValidator = WebSocketSubValidator;
+    type SubResponse = BitmexSubResponse;
+
+    fn base_url() -> &'static str {
+        BASE_URL_BITMEX
+    }
+
+    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        exchange_subs
+            .into_iter()
+            .map(|ExchangeSub { channel, market }| {
+                WsMessage::Text(
+                    json!({
+                        "op": "subscribe",
+                        "args": [format!("{}:{}", channel.as_ref(), market.as_ref())],
+                    })
+                    .to_string(),
+                )
+            })
+            .collect()
+    }
+
+    fn ping_interval() -> Option<PingInterval> {
+        Some(Self::PING_INTERVAL)
+    }
+}
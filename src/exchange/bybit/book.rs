@@ -0,0 +1,150 @@
+use crate::{
+    exchange::bybit::Bybit,
+    subscription::book::{Level, OrderBooksL2},
+    transformer::stateful::{OrderBookUpdate, StatefulTransformer},
+    ExchangeWsStream, StreamSelector,
+};
+use barter_integration::model::Instrument;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+impl<Server> StreamSelector<OrderBooksL2> for Bybit<Server>
+where
+    Server: Send + Sync,
+{
+    type Stream = ExchangeWsStream<StatefulTransformer<Self, OrderBooksL2, BybitOrderBookL2>>;
+}
+
+/// [`Bybit`](super::Bybit) real-time order book WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+/// ```json
+/// {
+///     "topic": "orderbook.50.BTCUSDT",
+///     "type": "snapshot",
+///     "ts": 1672324800000,
+///     "data": {
+///         "s": "BTCUSDT",
+///         "b": [["29000.0", "1.5"], ["28999.5", "0"]],
+///         "a": [["29001.0", "2.1"]],
+///         "u": 177400507
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitOrderBookL2 {
+    #[serde(rename = "topic")]
+    pub subscription_id: String,
+
+    #[serde(rename = "type")]
+    pub kind: BybitOrderBookL2Kind,
+
+    #[serde(deserialize_with = "de_millis_as_datetime_utc")]
+    pub ts: DateTime<Utc>,
+
+    pub data: BybitOrderBookL2Data,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BybitOrderBookL2Kind {
+    Snapshot,
+    Delta,
+}
+
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitOrderBookL2Data {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "b", deserialize_with = "de_levels")]
+    pub bids: Vec<Level>,
+    #[serde(rename = "a", deserialize_with = "de_levels")]
+    pub asks: Vec<Level>,
+    #[serde(rename = "u")]
+    pub sequence: u64,
+}
+
+fn de_levels<'de, D>(deserializer: D) -> Result<Vec<Level>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let raw = <Vec<[String; 2]> as Deserialize>::deserialize(deserializer)?;
+
+    raw.into_iter()
+        .map(|[price, amount]| {
+            let price = price
+                .parse()
+                .map_err(|error| serde::de::Error::custom(format!("invalid level price '{price}': {error}")))?;
+            let amount = amount
+                .parse()
+                .map_err(|error| serde::de::Error::custom(format!("invalid level amount '{amount}': {error}")))?;
+
+            Ok(Level::new(price, amount))
+        })
+        .collect()
+}
+
+fn de_millis_as_datetime_utc<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let millis = <i64 as serde::Deserialize>::deserialize(deserializer)?;
+    DateTime::from_timestamp_millis(millis)
+        .ok_or_else(|| serde::de::Error::custom(format!("invalid timestamp millis: {millis}")))
+}
+
+impl From<(Instrument, BybitOrderBookL2)> for (Instrument, OrderBookUpdate) {
+    fn from((instrument, book): (Instrument, BybitOrderBookL2)) -> Self {
+        let update = match book.kind {
+            BybitOrderBookL2Kind::Snapshot => OrderBookUpdate::Snapshot {
+                exchange_time: book.ts,
+                book: crate::subscription::book::OrderBook::new(
+                    book.data.sequence,
+                    book.data.bids,
+                    book.data.asks,
+                ),
+            },
+            BybitOrderBookL2Kind::Delta => OrderBookUpdate::Delta {
+                exchange_time: book.ts,
+                sequence: book.data.sequence,
+                bids: book.data.bids,
+                asks: book.data.asks,
+                // Bybit does not ship a running checksum, unlike OKX/Kraken.
+                checksum: None,
+            },
+        };
+
+        (instrument, update)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bybit_order_book_l2_snapshot() {
+        let input = r#"
+        {
+            "topic": "orderbook.50.BTCUSDT",
+            "type": "snapshot",
+            "ts": 1672324800000,
+            "data": {
+                "s": "BTCUSDT",
+                "b": [["29000.0", "1.5"]],
+                "a": [["29001.0", "2.1"]],
+                "u": 177400507
+            }
+        }
+        "#;
+
+        let actual = serde_json::from_str::<BybitOrderBookL2>(input).unwrap();
+
+        assert_eq!(actual.kind, BybitOrderBookL2Kind::Snapshot);
+        assert_eq!(actual.data.bids, vec![Level::new(29000.0, 1.5)]);
+        assert_eq!(actual.data.asks, vec![Level::new(29001.0, 2.1)]);
+        assert_eq!(actual.data.sequence, 177400507);
+        assert_eq!(actual.ts.timestamp_millis(), 1672324800000);
+    }
+}
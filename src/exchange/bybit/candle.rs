@@ -0,0 +1,101 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{bybit::Bybit, ExchangeId},
+    subscription::candle::{Candle, Candles},
+    transformer::StatelessTransformer,
+    ExchangeWsStream, StreamSelector,
+};
+use barter_integration::model::{Exchange, Instrument};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/kline>
+/// ```json
+/// {
+///     "topic": "kline.1.BTCUSDT",
+///     "data": [
+///         {
+///             "start": 1672324800000,
+///             "end": 1672324860000,
+///             "open": "16649.5",
+///             "high": "16652",
+///             "low": "16647.5",
+///             "close": "16650",
+///             "volume": "97.13",
+///             "confirm": false
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitCandle {
+    pub topic: String,
+    pub data: Vec<BybitCandleData>,
+}
+
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitCandleData {
+    pub start: i64,
+    pub end: i64,
+    #[serde(deserialize_with = "de_str_as_f64")]
+    pub open: f64,
+    #[serde(deserialize_with = "de_str_as_f64")]
+    pub high: f64,
+    #[serde(deserialize_with = "de_str_as_f64")]
+    pub low: f64,
+    #[serde(deserialize_with = "de_str_as_f64")]
+    pub close: f64,
+    #[serde(deserialize_with = "de_str_as_f64")]
+    pub volume: f64,
+}
+
+fn de_str_as_f64<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let input = <&str as serde::Deserialize>::deserialize(deserializer)?;
+    input.parse().map_err(serde::de::Error::custom)
+}
+
+impl<Server> StreamSelector<Candles> for Bybit<Server>
+where
+    Server: Send + Sync,
+{
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, Candles, BybitCandle>>;
+}
+
+impl From<(ExchangeId, Instrument, BybitCandle)> for MarketIter<Candle> {
+    fn from((exchange_id, instrument, candle): (ExchangeId, Instrument, BybitCandle)) -> Self {
+        Self(
+            candle
+                .data
+                .into_iter()
+                .map(|candle| {
+                    let start = DateTime::<Utc>::from_timestamp_millis(candle.start)
+                        .unwrap_or_else(Utc::now);
+                    let end = DateTime::<Utc>::from_timestamp_millis(candle.end)
+                        .unwrap_or_else(Utc::now);
+
+                    Ok(MarketEvent {
+                        exchange_time: end,
+                        received_time: Utc::now(),
+                        exchange: Exchange::from(exchange_id),
+                        instrument: instrument.clone(),
+                        kind: Candle {
+                            start_time: start,
+                            end_time: end,
+                            open: candle.open,
+                            high: candle.high,
+                            low: candle.low,
+                            close: candle.close,
+                            volume: candle.volume,
+                            // Bybit's kline payload does not carry a trade count.
+                            trade_count: 0,
+                        },
+                    })
+                })
+                .collect(),
+        )
+    }
+}
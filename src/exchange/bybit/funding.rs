@@ -0,0 +1,91 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{bybit::Bybit, ExchangeId},
+    subscription::funding::{Funding, FundingRates},
+    transformer::StatelessTransformer,
+    ExchangeWsStream, StreamSelector,
+};
+use barter_integration::model::{Exchange, Instrument};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/ticker>
+/// ```json
+/// {
+///     "topic": "tickers.BTCUSDT",
+///     "ts": 1672324800000,
+///     "data": {
+///         "symbol": "BTCUSDT",
+///         "fundingRate": "0.0001",
+///         "nextFundingTime": "1672387200000"
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitFunding {
+    pub topic: String,
+    #[serde(deserialize_with = "de_millis_as_datetime_utc")]
+    pub ts: DateTime<Utc>,
+    pub data: BybitFundingData,
+}
+
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitFundingData {
+    pub symbol: String,
+    #[serde(rename = "fundingRate", deserialize_with = "de_str_as_f64")]
+    pub rate: f64,
+    #[serde(rename = "nextFundingTime", deserialize_with = "de_str_millis_as_datetime_utc")]
+    pub next_funding_time: DateTime<Utc>,
+}
+
+fn de_str_as_f64<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let input = <&str as serde::Deserialize>::deserialize(deserializer)?;
+    input.parse().map_err(serde::de::Error::custom)
+}
+
+fn de_str_millis_as_datetime_utc<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let input = <&str as serde::Deserialize>::deserialize(deserializer)?;
+    let millis = input.parse::<i64>().map_err(serde::de::Error::custom)?;
+    DateTime::from_timestamp_millis(millis)
+        .ok_or_else(|| serde::de::Error::custom(format!("invalid timestamp millis: {millis}")))
+}
+
+fn de_millis_as_datetime_utc<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let millis = <i64 as serde::Deserialize>::deserialize(deserializer)?;
+    DateTime::from_timestamp_millis(millis)
+        .ok_or_else(|| serde::de::Error::custom(format!("invalid timestamp millis: {millis}")))
+}
+
+impl<Server> StreamSelector<FundingRates> for Bybit<Server>
+where
+    Server: Send + Sync,
+{
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, FundingRates, BybitFunding>>;
+}
+
+impl From<(ExchangeId, Instrument, BybitFunding)> for MarketIter<Funding> {
+    fn from((exchange_id, instrument, funding): (ExchangeId, Instrument, BybitFunding)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: funding.ts,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: Funding {
+                rate: funding.data.rate,
+                // Bybit's ticker stream only carries the currently applied rate.
+                next_rate: None,
+                next_funding_time: funding.data.next_funding_time,
+            },
+        })])
+    }
+}
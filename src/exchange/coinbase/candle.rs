@@ -0,0 +1,72 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::candle::Candle,
+};
+use barter_integration::model::{Exchange, Instrument};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#candles-channel>
+/// ```json
+/// {
+///     "type": "candle",
+///     "product_id": "BTC-USD",
+///     "start": "2023-02-18T09:27:00.000Z",
+///     "end": "2023-02-18T09:28:00.000Z",
+///     "open": "24564.5",
+///     "high": "24570.1",
+///     "low": "24560.0",
+///     "close": "24568.3",
+///     "volume": "12.4",
+///     "trades": 42
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseCandle {
+    #[serde(rename = "product_id")]
+    pub product_id: String,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    #[serde(deserialize_with = "de_str_as_f64")]
+    pub open: f64,
+    #[serde(deserialize_with = "de_str_as_f64")]
+    pub high: f64,
+    #[serde(deserialize_with = "de_str_as_f64")]
+    pub low: f64,
+    #[serde(deserialize_with = "de_str_as_f64")]
+    pub close: f64,
+    #[serde(deserialize_with = "de_str_as_f64")]
+    pub volume: f64,
+    pub trades: u64,
+}
+
+fn de_str_as_f64<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let input = <&str as serde::Deserialize>::deserialize(deserializer)?;
+    input.parse().map_err(serde::de::Error::custom)
+}
+
+impl From<(ExchangeId, Instrument, CoinbaseCandle)> for MarketIter<Candle> {
+    fn from((exchange_id, instrument, candle): (ExchangeId, Instrument, CoinbaseCandle)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: candle.end,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: Candle {
+                start_time: candle.start,
+                end_time: candle.end,
+                open: candle.open,
+                high: candle.high,
+                low: candle.low,
+                close: candle.close,
+                volume: candle.volume,
+                trade_count: candle.trades,
+            },
+        })])
+    }
+}
@@ -1,6 +1,10 @@
 use super::Coinbase;
 use crate::{
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{
+        candle::{Candles, Interval},
+        trade::PublicTrades,
+        Subscription,
+    },
     Identifier,
 };
 use serde::Serialize;
@@ -16,6 +20,20 @@ impl CoinbaseChannel {
     ///
     /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#match>
     pub const TRADES: Self = Self("matches");
+
+    /// [`Coinbase`] candles channel for the given [`Interval`], eg/ `"candles_1m"`.
+    ///
+    /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#candles-channel>
+    pub fn candles(interval: Interval) -> Self {
+        Self(match interval {
+            Interval::Minute1 => "candles_1m",
+            Interval::Minute5 => "candles_5m",
+            Interval::Minute15 => "candles_15m",
+            Interval::Hour1 => "candles_1h",
+            Interval::Hour4 => "candles_4h",
+            Interval::Day1 => "candles_1d",
+        })
+    }
 }
 
 impl Identifier<CoinbaseChannel> for Subscription<Coinbase, PublicTrades> {
@@ -24,6 +42,12 @@ impl Identifier<CoinbaseChannel> for Subscription<Coinbase, PublicTrades> {
     }
 }
 
+impl Identifier<CoinbaseChannel> for Subscription<Coinbase, Candles> {
+    fn id(&self) -> CoinbaseChannel {
+        CoinbaseChannel::candles(self.kind.0)
+    }
+}
+
 impl AsRef<str> for CoinbaseChannel {
     fn as_ref(&self) -> &str {
         self.0
@@ -1,11 +1,11 @@
 use self::{
-    channel::CoinbaseChannel, market::CoinbaseMarket, subscription::CoinbaseSubResponse,
-    trade::CoinbaseTrade,
+    candle::CoinbaseCandle, channel::CoinbaseChannel, market::CoinbaseMarket,
+    subscription::CoinbaseSubResponse, trade::CoinbaseTrade,
 };
 use crate::{
     exchange::{Connector, ExchangeId, ExchangeSub},
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::trade::PublicTrades,
+    subscription::{candle::Candles, trade::PublicTrades},
     transformer::StatelessTransformer,
     ExchangeWsStream, StreamSelector,
 };
@@ -14,6 +14,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::json;
 
 /// Todo:
+pub mod candle;
 pub mod channel;
 pub mod market;
 pub mod subscription;
@@ -62,3 +63,7 @@ impl Connector for Coinbase {
 impl StreamSelector<PublicTrades> for Coinbase {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, CoinbaseTrade>>;
 }
+
+impl StreamSelector<Candles> for Coinbase {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, Candles, CoinbaseCandle>>;
+}
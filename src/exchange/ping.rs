@@ -0,0 +1,15 @@
+use barter_integration::protocol::websocket::WsMessage;
+use std::time::Duration;
+
+/// Client-side keepalive configuration for exchanges that expect periodic application-level
+/// pings (eg/ BitMEX) or otherwise drop the connection as idle.
+///
+/// Returned by `Connector::ping_interval()`, which defaults to `None` so exchanges that rely
+/// purely on the WebSocket protocol's own ping/pong frames are unaffected. The streaming loop
+/// (see `subscriber::validator::WebSocketSubValidator::validate`) sends `message()` every
+/// `interval` for the lifetime of the connection.
+#[derive(Copy, Clone, Debug)]
+pub struct PingInterval {
+    pub interval: Duration,
+    pub message: fn() -> WsMessage,
+}
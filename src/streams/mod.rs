@@ -0,0 +1,3 @@
+/// Connection-manager subsystem that shards subscriptions for a single exchange across a
+/// bounded set of WebSocket connections and merges their output into one stream.
+pub mod multi;
@@ -0,0 +1,107 @@
+use crate::{
+    event::MarketEvent,
+    exchange::Connector,
+    subscriber::{backoff::ReconnectBackoffConfig, reconnect::ReconnectingStream},
+    subscription::{SubKind, Subscription},
+    StreamSelector,
+};
+use barter_integration::error::SocketError;
+use futures::Stream;
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use stream_unordered::{StreamUnordered, StreamYield};
+use tracing::warn;
+
+/// Builder that shards a large set of [`Subscription`]s across several underlying WebSocket
+/// connections - respecting `max_subs_per_connection`, the per-venue subscription cap - and
+/// produces a single [`MultiStream`] that merges every connection's output.
+///
+/// Each underlying connection is wrapped in a [`ReconnectingStream`], so a dropped socket is
+/// rebuilt in place rather than stalling the others - see [`MultiStream`] for why that means
+/// per-connection liveness isn't something a caller can observe here.
+pub struct MultiStreamBuilder<Exchange, Kind> {
+    subscriptions: Vec<Subscription<Exchange, Kind>>,
+    max_subs_per_connection: usize,
+    backoff: ReconnectBackoffConfig,
+}
+
+impl<Exchange, Kind> MultiStreamBuilder<Exchange, Kind>
+where
+    Exchange: Connector + StreamSelector<Kind> + Send + 'static,
+    Kind: SubKind + Send + 'static,
+    Exchange::Stream: Stream<Item = Result<MarketEvent<Kind::Event>, SocketError>> + Send + Unpin + 'static,
+{
+    pub fn new(max_subs_per_connection: usize) -> Self {
+        Self {
+            subscriptions: Vec::new(),
+            max_subs_per_connection,
+            backoff: ReconnectBackoffConfig::default(),
+        }
+    }
+
+    /// Add [`Subscription`]s to be sharded across connections once [`init`](Self::init) runs.
+    pub fn add(mut self, subscriptions: Vec<Subscription<Exchange, Kind>>) -> Self {
+        self.subscriptions.extend(subscriptions);
+        self
+    }
+
+    /// Override the default reconnection backoff used for every sharded connection.
+    pub fn backoff(mut self, backoff: ReconnectBackoffConfig) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Initialise one underlying connection per `max_subs_per_connection`-sized shard of the
+    /// accumulated [`Subscription`]s, and merge their output into a single [`MultiStream`].
+    ///
+    /// Each shard's connection is a [`ReconnectingStream`], so a dropped WebSocket is rebuilt
+    /// (resubscribing to that shard) rather than silently ending that shard's contribution.
+    pub async fn init(self) -> Result<MultiStream<ReconnectingStream<Exchange, Kind>>, SocketError> {
+        let mut streams = StreamUnordered::new();
+
+        for shard in self.subscriptions.chunks(self.max_subs_per_connection.max(1)) {
+            let stream = ReconnectingStream::new(Exchange::ID, self.backoff, shard.to_vec());
+            streams.insert(stream);
+        }
+
+        Ok(MultiStream { streams })
+    }
+}
+
+/// Unified stream produced by [`MultiStreamBuilder::init`], merging the output of every inner
+/// per-connection stream.
+///
+/// There is deliberately no per-connection liveness API (eg/ a `dropped_connections()`): every
+/// inner stream is a [`ReconnectingStream`], which never itself ends - a dropped socket is
+/// rebuilt in place rather than surfacing as a finished [`StreamYield`] - so from here a shard
+/// that is stuck reconnecting forever looks identical to one that is healthy but momentarily
+/// idle. Watch `tracing`'s `warn!` output from [`ReconnectingStream`] if that distinction matters.
+pub struct MultiStream<S> {
+    streams: StreamUnordered<S>,
+}
+
+impl<S> Stream for MultiStream<S>
+where
+    S: Stream + Unpin,
+{
+    type Item = S::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            return match Pin::new(&mut self.streams).poll_next(cx) {
+                Poll::Ready(Some((_id, StreamYield::Item(item)))) => Poll::Ready(Some(item)),
+                Poll::Ready(Some((id, StreamYield::Finished(_)))) => {
+                    // Unreachable for `ReconnectingStream`-backed shards (it never ends), but
+                    // `StreamUnordered` is generic, so log rather than silently dropping a
+                    // connection id if some other `S` genuinely finishes.
+                    warn!(connection_id = id, "inner MultiStream connection finished");
+                    continue;
+                }
+                Poll::Ready(None) => Poll::Ready(None),
+                Poll::Pending => Poll::Pending,
+            };
+        }
+    }
+}
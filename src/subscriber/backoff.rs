@@ -0,0 +1,109 @@
+use std::time::Duration;
+
+/// Configuration for the capped exponential backoff used by the reconnection subsystem (see
+/// `subscriber::reconnect`) when a WebSocket connection drops or fails subscription validation.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ReconnectBackoffConfig {
+    /// Delay before the first reconnection attempt.
+    pub base_delay: Duration,
+    /// Upper bound the delay is capped at, regardless of how many consecutive failures occur.
+    pub max_delay: Duration,
+}
+
+impl Default for ReconnectBackoffConfig {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Stateful capped exponential backoff calculator: each consecutive failure doubles the delay
+/// (up to `max_delay`), and a successful reconnection resets the delay back to `base_delay`.
+///
+/// A small amount of jitter is added to each delay to avoid many disconnected clients
+/// reconnecting to an exchange in lockstep.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ReconnectBackoff {
+    config: ReconnectBackoffConfig,
+    attempt: u32,
+}
+
+impl ReconnectBackoff {
+    pub fn new(config: ReconnectBackoffConfig) -> Self {
+        Self { config, attempt: 0 }
+    }
+
+    /// Return the delay to wait before the next reconnection attempt, and advance the internal
+    /// attempt counter so the subsequent call returns a longer delay (up to `max_delay`).
+    pub fn next_delay(&mut self) -> Duration {
+        let uncapped = self
+            .config
+            .base_delay
+            .saturating_mul(1u32.checked_shl(self.attempt).unwrap_or(u32::MAX));
+
+        self.attempt = self.attempt.saturating_add(1);
+
+        let delay = uncapped.min(self.config.max_delay);
+
+        delay + jitter(delay)
+    }
+
+    /// Reset the backoff delay back to `base_delay` after a successful reconnection.
+    pub fn reset(&mut self) {
+        self.attempt = 0;
+    }
+}
+
+/// Add up to 20% jitter to a backoff `delay`, drawn from std's randomly seeded
+/// [`RandomState`](std::collections::hash_map::RandomState) so this module stays free of a
+/// `rand` dependency while separate clients still get different offsets.
+fn jitter(delay: Duration) -> Duration {
+    use std::hash::{BuildHasher, Hasher};
+
+    let max_jitter_nanos = delay.as_nanos() as u64 / 5;
+    let random = std::collections::hash_map::RandomState::new().build_hasher().finish();
+    Duration::from_nanos(random % (max_jitter_nanos + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_delay_doubles_and_caps() {
+        let config = ReconnectBackoffConfig {
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(8),
+        };
+        let mut backoff = ReconnectBackoff::new(config);
+
+        // TC0: first delay is ~ base_delay (plus jitter)
+        assert!(backoff.next_delay() >= Duration::from_secs(1));
+        // TC1: second delay ~ 2x base_delay
+        assert!(backoff.next_delay() >= Duration::from_secs(2));
+        // TC2: third delay ~ 4x base_delay
+        assert!(backoff.next_delay() >= Duration::from_secs(4));
+        // TC3: delay is capped at max_delay, even after many more failures
+        for _ in 0..10 {
+            backoff.next_delay();
+        }
+        assert!(backoff.next_delay() < Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_reset_returns_backoff_to_base_delay() {
+        let config = ReconnectBackoffConfig {
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+        };
+        let mut backoff = ReconnectBackoff::new(config);
+
+        backoff.next_delay();
+        backoff.next_delay();
+        backoff.reset();
+
+        assert!(backoff.next_delay() < Duration::from_secs(2));
+    }
+}
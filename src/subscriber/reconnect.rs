@@ -0,0 +1,183 @@
+use crate::{
+    event::MarketEvent,
+    exchange::{Connector, ExchangeId},
+    subscriber::backoff::{ReconnectBackoff, ReconnectBackoffConfig},
+    subscription::{SubKind, Subscription},
+    StreamSelector,
+};
+use barter_integration::error::SocketError;
+use futures::Stream;
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tracing::warn;
+
+/// Drive the supplied `connect` closure - which should perform the full
+/// `Connector::base_url()` connection, `Connector::requests(...)` subscription send, and
+/// `SubscriptionValidator::validate` dance - retrying on failure with a capped exponential
+/// backoff (see [`ReconnectBackoff`]) until it eventually succeeds.
+///
+/// Used to wrap the stream-building layer (`ExchangeWsStream`) so a dropped connection or a
+/// failed subscription validation is transparently retried rather than propagated to the
+/// consumer. Callers building stateful kinds (eg/ `subscription::book::OrderBooksL2`) should
+/// construct a fresh `transformer::stateful::StatefulTransformer` inside `connect` so cached
+/// state is invalidated and a new snapshot is requested on every reconnection.
+pub async fn connect_with_backoff<Connect, Fut, Output>(
+    exchange: ExchangeId,
+    config: ReconnectBackoffConfig,
+    mut connect: Connect,
+) -> Output
+where
+    Connect: FnMut() -> Fut,
+    Fut: Future<Output = Result<Output, SocketError>>,
+{
+    let mut backoff = ReconnectBackoff::new(config);
+
+    loop {
+        match connect().await {
+            Ok(output) => {
+                backoff.reset();
+                break output;
+            }
+            Err(error) => {
+                let delay = backoff.next_delay();
+                warn!(
+                    %exchange,
+                    %error,
+                    reconnect_in = ?delay,
+                    "WebSocket connection/subscription validation failed, reconnecting after backoff"
+                );
+                tokio::time::sleep(delay).await;
+            }
+        }
+    }
+}
+
+/// Either polling a live `Exchange::Stream`, or awaiting [`connect_with_backoff`] to rebuild one
+/// after the previous connection ended.
+enum ReconnectState<S> {
+    Connecting(Pin<Box<dyn Future<Output = S> + Send>>),
+    Streaming(S),
+}
+
+/// [`Stream`] wrapper that transparently rebuilds the underlying `Exchange::Stream` - resending
+/// every [`Subscription`] in `shard` - whenever it ends, or whenever it yields a
+/// `SocketError::Subscribe` item (eg/ an `OrderBook` checksum mismatch - see
+/// `transformer::stateful::StatefulTransformer` - or any other error a `Transformer` considers
+/// fatal to the current connection's subscription state), rather than letting the caller
+/// observe a terminated connection or get stuck reprocessing a desynced book forever. Each
+/// reconnection attempt is paced by [`connect_with_backoff`], so a shard that keeps failing does
+/// not hammer the exchange.
+///
+/// Used by [`MultiStreamBuilder`](crate::streams::multi::MultiStreamBuilder) to give every
+/// sharded connection independent reconnect behaviour.
+pub struct ReconnectingStream<Exchange, Kind>
+where
+    Exchange: Connector + StreamSelector<Kind>,
+    Kind: SubKind,
+{
+    exchange: ExchangeId,
+    backoff: ReconnectBackoffConfig,
+    shard: Vec<Subscription<Exchange, Kind>>,
+    state: ReconnectState<Exchange::Stream>,
+}
+
+impl<Exchange, Kind> ReconnectingStream<Exchange, Kind>
+where
+    Exchange: Connector + StreamSelector<Kind> + Send + 'static,
+    Kind: SubKind + Send + 'static,
+    Exchange::Stream: Stream + Send + Unpin + 'static,
+{
+    pub fn new(exchange: ExchangeId, backoff: ReconnectBackoffConfig, shard: Vec<Subscription<Exchange, Kind>>) -> Self {
+        Self {
+            exchange,
+            backoff,
+            state: ReconnectState::Connecting(Self::connect(exchange, backoff, shard.clone())),
+            shard,
+        }
+    }
+
+    fn connect(
+        exchange: ExchangeId,
+        backoff: ReconnectBackoffConfig,
+        shard: Vec<Subscription<Exchange, Kind>>,
+    ) -> Pin<Box<dyn Future<Output = Exchange::Stream> + Send>> {
+        Box::pin(connect_with_backoff(exchange, backoff, move || {
+            Exchange::Stream::init(shard.clone())
+        }))
+    }
+}
+
+impl<Exchange, Kind> Stream for ReconnectingStream<Exchange, Kind>
+where
+    Exchange: Connector + StreamSelector<Kind> + Send + 'static,
+    Kind: SubKind + Send + 'static,
+    Exchange::Stream: Stream<Item = Result<MarketEvent<Kind::Event>, SocketError>> + Send + Unpin + 'static,
+{
+    type Item = Result<MarketEvent<Kind::Event>, SocketError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            match &mut this.state {
+                ReconnectState::Connecting(connecting) => match connecting.as_mut().poll(cx) {
+                    Poll::Ready(stream) => this.state = ReconnectState::Streaming(stream),
+                    Poll::Pending => return Poll::Pending,
+                },
+                ReconnectState::Streaming(stream) => match Pin::new(stream).poll_next(cx) {
+                    // A `Transformer` (eg/ `StatefulTransformer` on checksum mismatch) signals
+                    // its local state is corrupted via `SocketError::Subscribe` - treat that the
+                    // same as a dropped connection rather than looping forever replaying an
+                    // error from a book that was never reset.
+                    Poll::Ready(Some(Err(SocketError::Subscribe(reason)))) => {
+                        warn!(
+                            exchange = %this.exchange,
+                            %reason,
+                            "exchange stream reported a fatal subscription error, reconnecting"
+                        );
+                        this.state =
+                            ReconnectState::Connecting(Self::connect(this.exchange, this.backoff, this.shard.clone()));
+                    }
+                    Poll::Ready(Some(item)) => return Poll::Ready(Some(item)),
+                    Poll::Ready(None) => {
+                        warn!(exchange = %this.exchange, "exchange WebSocket stream ended, reconnecting");
+                        this.state =
+                            ReconnectState::Connecting(Self::connect(this.exchange, this.backoff, this.shard.clone()));
+                    }
+                    Poll::Pending => return Poll::Pending,
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn test_connect_with_backoff_retries_until_success() {
+        let attempts = AtomicUsize::new(0);
+
+        let config = ReconnectBackoffConfig {
+            base_delay: std::time::Duration::from_millis(1),
+            max_delay: std::time::Duration::from_millis(5),
+        };
+
+        let output = connect_with_backoff(ExchangeId::Coinbase, config, || async {
+            if attempts.fetch_add(1, Ordering::SeqCst) < 2 {
+                Err(SocketError::Subscribe("not yet".to_string()))
+            } else {
+                Ok("connected")
+            }
+        })
+        .await;
+
+        assert_eq!(output, "connected");
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+}
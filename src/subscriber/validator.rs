@@ -6,16 +6,22 @@ use async_trait::async_trait;
 use barter_integration::{
     error::SocketError,
     protocol::{
-        websocket::{WebSocket, WebSocketParser},
+        websocket::{WebSocket, WebSocketParser, WsMessage},
         StreamParser,
     },
     Validator,
 };
-use futures::StreamExt;
+use futures::{SinkExt, StreamExt};
 use serde::{Deserialize, Serialize};
-use tracing::debug;
+use tracing::{debug, warn};
 
 /// Todo:
+///
+/// On success, also returns the [`PingScheduler`] driven during validation so the caller's
+/// long-running read loop (`ExchangeWsStream`) can keep ticking it for the lifetime of the
+/// connection - `Connector::ping_interval()` exchanges (eg/ BitMEX) drop idle connections well
+/// before a single subscription handshake would ever need to reconnect, so the keepalive can't
+/// end when `validate` returns.
 #[async_trait]
 pub trait SubscriptionValidator {
     type Parser: StreamParser;
@@ -23,12 +29,71 @@ pub trait SubscriptionValidator {
     async fn validate<Exchange, Kind>(
         map: SubscriptionMap<Exchange, Kind>,
         websocket: &mut WebSocket,
-    ) -> Result<SubscriptionMap<Exchange, Kind>, SocketError>
+    ) -> Result<(SubscriptionMap<Exchange, Kind>, PingScheduler), SocketError>
     where
         Exchange: Connector + Send,
         Kind: SubKind + Send;
 }
 
+/// Schedules an exchange's optional client-side keepalive ping (see
+/// [`Connector::ping_interval`]), designed to be polled from a `tokio::select!` for as long as
+/// the underlying [`WebSocket`] connection is open - both during
+/// [`WebSocketSubValidator::validate`]'s handshake and afterwards, in `ExchangeWsStream`'s
+/// long-running read loop. Exchanges with no configured ping interval get an inert scheduler
+/// whose [`tick`](Self::tick) never resolves, so it is always safe to include unconditionally.
+pub struct PingScheduler {
+    ticker: Option<tokio::time::Interval>,
+    message: Option<fn() -> WsMessage>,
+}
+
+impl PingScheduler {
+    pub fn new<Exchange>() -> Self
+    where
+        Exchange: Connector,
+    {
+        match Exchange::ping_interval() {
+            Some(ping) => Self {
+                ticker: Some(tokio::time::interval(ping.interval)),
+                message: Some(ping.message),
+            },
+            None => Self {
+                ticker: None,
+                message: None,
+            },
+        }
+    }
+
+    /// Whether this exchange has a configured [`PingInterval`](crate::exchange::ping::PingInterval) at all.
+    pub fn is_active(&self) -> bool {
+        self.ticker.is_some()
+    }
+
+    /// Await the next scheduled ping tick. Pending forever if this exchange has no configured
+    /// ping interval.
+    pub async fn tick(&mut self) {
+        match self.ticker.as_mut() {
+            Some(ticker) => {
+                ticker.tick().await;
+            }
+            None => std::future::pending::<()>().await,
+        }
+    }
+
+    /// Send this exchange's keepalive message over `websocket`, logging (rather than failing)
+    /// on error - a transient send failure shouldn't itself tear down the connection, since the
+    /// caller's own read loop will observe the connection dying via its next `websocket.next()`.
+    pub async fn send<Exchange>(&self, websocket: &mut WebSocket)
+    where
+        Exchange: Connector,
+    {
+        if let Some(message) = self.message {
+            if let Err(error) = websocket.send(message()).await {
+                warn!(exchange = %Exchange::ID, %error, "failed to send keepalive ping");
+            }
+        }
+    }
+}
+
 /// Todo:
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct WebSocketSubValidator;
@@ -40,7 +105,7 @@ impl SubscriptionValidator for WebSocketSubValidator {
     async fn validate<Exchange, Kind>(
         map: SubscriptionMap<Exchange, Kind>,
         websocket: &mut WebSocket,
-    ) -> Result<SubscriptionMap<Exchange, Kind>, SocketError>
+    ) -> Result<(SubscriptionMap<Exchange, Kind>, PingScheduler), SocketError>
     where
         Exchange: Connector + Send,
         Kind: SubKind + Send,
@@ -52,11 +117,18 @@ impl SubscriptionValidator for WebSocketSubValidator {
         // Parameter to keep track of successful Subscription outcomes
         let mut success_responses = 0usize;
 
+        // Some exchanges (eg/ BitMEX) require the client to send periodic application-level
+        // pings or they drop the connection, so schedule the optional keepalive here too. This
+        // same `PingScheduler` is handed back to the caller on success so `ExchangeWsStream`'s
+        // long-running read loop can keep ticking it after validation finishes - the handshake
+        // window is far shorter than the idle timeout it's guarding against.
+        let mut ping_scheduler = PingScheduler::new::<Exchange>();
+
         loop {
             // Break if all Subscriptions were a success
             if success_responses == expected_responses {
                 debug!(exchange = %Exchange::ID, "validated exchange WebSocket subscriptions");
-                break Ok(map);
+                break Ok((map, ping_scheduler));
             }
 
             tokio::select! {
@@ -66,6 +138,10 @@ impl SubscriptionValidator for WebSocketSubValidator {
                         format!("subscription validation timeout reached: {:?}", timeout)
                     ))
                 },
+                // Send the exchange's configured keepalive message, if any, whenever it fires
+                _ = ping_scheduler.tick(), if ping_scheduler.is_active() => {
+                    ping_scheduler.send::<Exchange>(websocket).await;
+                },
                 // Parse incoming messages and determine subscription outcomes
                 message = websocket.next() => {
                     let response = match message {
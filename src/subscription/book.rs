@@ -0,0 +1,175 @@
+use crate::subscription::SubKind;
+use barter_integration::error::SocketError;
+use serde::{Deserialize, Serialize};
+
+/// Format a price/amount `f64` the way exchanges render it in their raw checksum payloads:
+/// an integer-valued level (eg/ `200.0`) is rendered without a trailing `.0`.
+fn fmt_level_price(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value as i64)
+    } else {
+        format!("{value}")
+    }
+}
+
+/// [`SubKind`] that yields a normalised [`OrderBook`] snapshot built from an initial exchange
+/// snapshot plus subsequent incremental delta updates.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct OrderBooksL2;
+
+impl SubKind for OrderBooksL2 {
+    type Event = OrderBook;
+}
+
+/// Normalised Barter [`OrderBook`] snapshot - the current best known `bids` and `asks` for an
+/// instrument, maintained locally by applying an initial snapshot followed by incremental
+/// delta updates (see `transformer::stateful::StatefulTransformer`).
+#[derive(Clone, PartialEq, PartialOrd, Default, Debug, Deserialize, Serialize)]
+pub struct OrderBook {
+    pub sequence: u64,
+    pub bids: Vec<Level>,
+    pub asks: Vec<Level>,
+}
+
+impl OrderBook {
+    pub fn new(sequence: u64, bids: Vec<Level>, asks: Vec<Level>) -> Self {
+        Self {
+            sequence,
+            bids,
+            asks,
+        }
+    }
+
+    /// Apply a single side delta `Level` to this [`OrderBook`], inserting or updating the level
+    /// by price, or removing it entirely when `amount` is zero.
+    pub fn upsert_bid(&mut self, level: Level) {
+        Self::upsert_level(&mut self.bids, level, true)
+    }
+
+    /// Apply a single side delta `Level` to this [`OrderBook`], inserting or updating the level
+    /// by price, or removing it entirely when `amount` is zero.
+    pub fn upsert_ask(&mut self, level: Level) {
+        Self::upsert_level(&mut self.asks, level, false)
+    }
+
+    /// Build the exchange checksum verification string by interleaving the best `levels`
+    /// `bids` and `asks` as `bidPrice:bidSize:askPrice:askSize:...`, truncating to however many
+    /// levels exist on each side, and return the CRC32 (as a signed `i32`) of its ASCII bytes.
+    ///
+    /// Used to detect local [`OrderBook`] desync against an exchange-supplied running checksum
+    /// (eg/ OKX, Kraken).
+    pub fn checksum(&self, levels: usize) -> i32 {
+        let mut entries = Vec::with_capacity(levels * 2);
+
+        for index in 0..levels {
+            if let Some(bid) = self.bids.get(index) {
+                entries.push(format!("{}:{}", fmt_level_price(bid.price), fmt_level_price(bid.amount)));
+            }
+            if let Some(ask) = self.asks.get(index) {
+                entries.push(format!("{}:{}", fmt_level_price(ask.price), fmt_level_price(ask.amount)));
+            }
+        }
+
+        crc32fast::hash(entries.join(":").as_bytes()) as i32
+    }
+
+    /// Verify this [`OrderBook`]'s checksum over the top `levels` against the exchange-supplied
+    /// `expected` checksum, returning a [`SocketError`] if they disagree so the caller can treat
+    /// the local book as corrupted (drop it and request a fresh snapshot).
+    pub fn verify_checksum(&self, expected: i32, levels: usize) -> Result<(), SocketError> {
+        let actual = self.checksum(levels);
+
+        if actual == expected {
+            Ok(())
+        } else {
+            Err(SocketError::Subscribe(format!(
+                "OrderBook checksum mismatch, local book is corrupted: expected {expected}, computed {actual}"
+            )))
+        }
+    }
+
+    fn upsert_level(levels: &mut Vec<Level>, level: Level, is_bid: bool) {
+        let index = levels.iter().position(|existing| existing.price == level.price);
+
+        if level.amount == 0.0 {
+            if let Some(index) = index {
+                levels.remove(index);
+            }
+            return;
+        }
+
+        match index {
+            Some(index) => levels[index] = level,
+            None => levels.push(level),
+        }
+
+        // Keep levels sorted best-to-worst: bids descending, asks ascending.
+        if is_bid {
+            levels.sort_by(|a, b| b.price.partial_cmp(&a.price).unwrap());
+        } else {
+            levels.sort_by(|a, b| a.price.partial_cmp(&b.price).unwrap());
+        }
+    }
+}
+
+/// Normalised Barter order book [`Level`], a single `price` / `amount` pair.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Level {
+    pub price: f64,
+    pub amount: f64,
+}
+
+impl Level {
+    pub fn new(price: f64, amount: f64) -> Self {
+        Self { price, amount }
+    }
+}
+
+impl From<(f64, f64)> for Level {
+    fn from((price, amount): (f64, f64)) -> Self {
+        Self::new(price, amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upsert_bid_inserts_and_updates() {
+        let mut book = OrderBook::new(0, vec![], vec![]);
+
+        book.upsert_bid(Level::new(100.0, 1.0));
+        book.upsert_bid(Level::new(101.0, 2.0));
+        assert_eq!(book.bids, vec![Level::new(101.0, 2.0), Level::new(100.0, 1.0)]);
+
+        // TC1: updating an existing price replaces the amount in place
+        book.upsert_bid(Level::new(100.0, 5.0));
+        assert_eq!(book.bids, vec![Level::new(101.0, 2.0), Level::new(100.0, 5.0)]);
+    }
+
+    #[test]
+    fn test_upsert_ask_removes_zero_amount_level() {
+        let mut book = OrderBook::new(0, vec![], vec![Level::new(100.0, 1.0)]);
+
+        book.upsert_ask(Level::new(100.0, 0.0));
+
+        assert_eq!(book.asks, vec![]);
+    }
+
+    #[test]
+    fn test_verify_checksum_ok_and_mismatch() {
+        let book = OrderBook::new(
+            0,
+            vec![Level::new(100.0, 1.0)],
+            vec![Level::new(101.0, 2.0)],
+        );
+
+        let expected = book.checksum(25);
+
+        assert!(book.verify_checksum(expected, 25).is_ok());
+        assert!(book.verify_checksum(expected.wrapping_add(1), 25).is_err());
+    }
+}
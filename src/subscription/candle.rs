@@ -0,0 +1,36 @@
+use crate::subscription::SubKind;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`SubKind`] that yields a normalised OHLCV [`Candle`], parameterised by the candlestick
+/// [`Interval`] to subscribe to.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Candles(pub Interval);
+
+impl SubKind for Candles {
+    type Event = Candle;
+}
+
+/// Candlestick / kline interval supported by the venues this crate normalises `Candles` for.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum Interval {
+    Minute1,
+    Minute5,
+    Minute15,
+    Hour1,
+    Hour4,
+    Day1,
+}
+
+/// Normalised Barter OHLCV [`Candle`].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Candle {
+    pub start_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+    pub trade_count: u64,
+}
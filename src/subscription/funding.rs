@@ -0,0 +1,22 @@
+use crate::subscription::SubKind;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`SubKind`] that yields a normalised [`Funding`] rate update for a perpetual swap
+/// instrument.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-funding-rate-channel>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct FundingRates;
+
+impl SubKind for FundingRates {
+    type Event = Funding;
+}
+
+/// Normalised Barter perpetual swap [`Funding`] rate update.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Funding {
+    pub rate: f64,
+    pub next_rate: Option<f64>,
+    pub next_funding_time: DateTime<Utc>,
+}
@@ -0,0 +1,175 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{Connector, ExchangeId},
+    subscription::book::{Level, OrderBook},
+    Transformer,
+};
+use barter_integration::{error::SocketError, model::Instrument};
+use chrono::{DateTime, Utc};
+use std::{collections::HashMap, marker::PhantomData};
+
+/// Describes how a raw exchange order book payload should mutate the locally maintained
+/// [`OrderBook`] - either a full resync, or an incremental change applied on top of it.
+///
+/// Both variants carry the exchange-reported `exchange_time` the update was generated at, so
+/// the emitted `MarketEvent::exchange_time` reflects the venue's own clock rather than the
+/// time this transformer happened to process it.
+#[derive(Clone, Debug)]
+pub enum OrderBookUpdate {
+    /// Replace the local [`OrderBook`] entirely (eg/ the initial snapshot, or a resync after
+    /// the local book is detected to have desynced).
+    Snapshot {
+        exchange_time: DateTime<Utc>,
+        book: OrderBook,
+    },
+    /// Apply incremental `bids` / `asks` [`Level`] changes on top of the existing [`OrderBook`].
+    Delta {
+        exchange_time: DateTime<Utc>,
+        sequence: u64,
+        bids: Vec<Level>,
+        asks: Vec<Level>,
+        /// Exchange-supplied running checksum (eg/ OKX, Kraken), verified against the local
+        /// book after the delta is applied when [`ChecksumConfig::enabled`].
+        checksum: Option<i32>,
+    },
+}
+
+/// Per-exchange configuration for the optional [`OrderBook`] checksum verification carried
+/// out by [`StatefulTransformer`] after every [`OrderBookUpdate::Delta`]. Formats differ
+/// slightly between venues, so both whether checksums are verified and how many levels are
+/// included are configurable.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct ChecksumConfig {
+    pub enabled: bool,
+    pub levels: usize,
+}
+
+impl ChecksumConfig {
+    pub const fn disabled() -> Self {
+        Self {
+            enabled: false,
+            levels: 0,
+        }
+    }
+}
+
+impl Default for ChecksumConfig {
+    fn default() -> Self {
+        Self::disabled()
+    }
+}
+
+/// Source of the [`ChecksumConfig`] used by `StatefulTransformer::default()`.
+///
+/// Every [`Connector`] gets this via a blanket impl, so every exchange uses the default
+/// [`ChecksumConfig::disabled`]; the blanket impl leaves no room for an exchange-specific
+/// [`checksum_config`](Self::checksum_config). Verification is turned on only by building the
+/// transformer with [`StatefulTransformer::new`] and an enabled [`ChecksumConfig`].
+pub trait ChecksumSource {
+    fn checksum_config() -> ChecksumConfig {
+        ChecksumConfig::disabled()
+    }
+}
+
+impl<T> ChecksumSource for T where T: Connector {}
+
+/// `Stateful` [`Transformer`] that maintains a local [`OrderBook`] per [`Instrument`], built by
+/// applying an initial snapshot and subsequent incremental [`OrderBookUpdate::Delta`]s, and
+/// emits the current top-of-book [`OrderBook`] as a [`MarketEvent`] after every update.
+#[derive(Debug)]
+pub struct StatefulTransformer<Exchange, Kind, Input> {
+    books: HashMap<Instrument, OrderBook>,
+    checksum: ChecksumConfig,
+    marker: PhantomData<(Exchange, Kind, Input)>,
+}
+
+impl<Exchange, Kind, Input> StatefulTransformer<Exchange, Kind, Input> {
+    pub fn new(checksum: ChecksumConfig) -> Self {
+        Self {
+            books: HashMap::new(),
+            checksum,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<Exchange, Kind, Input> Default for StatefulTransformer<Exchange, Kind, Input>
+where
+    Exchange: ChecksumSource,
+{
+    fn default() -> Self {
+        Self::new(Exchange::checksum_config())
+    }
+}
+
+impl<Exchange, Kind, Input> Transformer for StatefulTransformer<Exchange, Kind, Input>
+where
+    Exchange: Connector,
+    Input: Into<(Instrument, OrderBookUpdate)>,
+{
+    type Error = SocketError;
+    type Input = Input;
+    type Output = MarketEvent<OrderBook>;
+    type OutputIter = MarketIter<OrderBook>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        let (instrument, update) = input.into();
+
+        let book = self
+            .books
+            .entry(instrument.clone())
+            .or_insert_with(|| OrderBook::new(0, vec![], vec![]));
+
+        let (exchange_time, expected_checksum) = match update {
+            OrderBookUpdate::Snapshot { exchange_time, book: snapshot } => {
+                *book = snapshot;
+                (exchange_time, None)
+            }
+            OrderBookUpdate::Delta {
+                exchange_time,
+                sequence,
+                bids,
+                asks,
+                checksum,
+            } => {
+                book.sequence = sequence;
+                bids.into_iter().for_each(|level| book.upsert_bid(level));
+                asks.into_iter().for_each(|level| book.upsert_ask(level));
+                (exchange_time, checksum)
+            }
+        };
+
+        if let (true, Some(expected)) = (self.checksum.enabled, expected_checksum) {
+            if let Err(error) = book.verify_checksum(expected, self.checksum.levels) {
+                // Local book is corrupted - drop it so the next snapshot rebuilds it from
+                // scratch, and surface the error so the stream layer can trigger a resubscribe.
+                self.books.remove(&instrument);
+                return MarketIter(vec![Err(error)]);
+            }
+        }
+
+        MarketIter(vec![Ok(MarketEvent {
+            exchange_time,
+            received_time: Utc::now(),
+            exchange: Exchange::ID.into(),
+            instrument,
+            kind: book.clone(),
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::book::Level;
+
+    #[test]
+    fn test_upsert_bid_delta_levels_upserts_and_removes() {
+        let mut book = OrderBook::new(0, vec![Level::new(100.0, 1.0)], vec![]);
+        let bids = vec![Level::new(100.0, 0.0), Level::new(99.0, 2.0)];
+
+        bids.into_iter().for_each(|level| book.upsert_bid(level));
+
+        assert_eq!(book.bids, vec![Level::new(99.0, 2.0)]);
+    }
+}